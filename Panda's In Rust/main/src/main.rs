@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt;
 use std::process;
@@ -9,6 +11,7 @@ enum ColumnVal {
     Two(bool),
     Three(f64),
     Four(i64),
+    Missing, // No value: a read_csv null token, a cell absent from a diagonal concat, or an empty group aggregate
 }
 
 #[derive(Debug)]
@@ -19,6 +22,72 @@ struct DataFrame {
     rows: Vec<Vec<ColumnVal>>, // Whatever the row value is the table will reflect it
 }
 
+// Aggregation functions available to pivot
+#[derive(Debug, Clone, Copy)]
+enum AggFunc {
+    Sum,
+    Mean,
+    First,
+    Count,
+}
+
+// How concat reconciles frames whose labels don't line up exactly
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConcatHow {
+    Strict,   // every frame must share the same labels/types as self
+    Diagonal, // union of labels; cells absent from a frame are filled with Missing
+}
+
+// Builds a hashable key out of a ColumnVal so it can be used to group rows,
+// since ColumnVal itself doesn't derive Hash/Eq
+fn column_val_key(val: &ColumnVal) -> String {
+    match val {
+        ColumnVal::One(s) => format!("s:{}", s),
+        ColumnVal::Two(b) => format!("b:{}", b),
+        ColumnVal::Three(f) => format!("f:{}", f.to_bits()),
+        ColumnVal::Four(i) => format!("i:{}", i),
+        ColumnVal::Missing => "missing".to_string(),
+    }
+}
+
+// Turns a ColumnVal into a string suitable for use as a generated column label
+fn column_val_label(val: &ColumnVal) -> String {
+    match val {
+        ColumnVal::One(s) => s.clone(),
+        ColumnVal::Two(b) => b.to_string(),
+        ColumnVal::Three(f) => f.to_string(),
+        ColumnVal::Four(i) => i.to_string(),
+        ColumnVal::Missing => "NaN".to_string(),
+    }
+}
+
+// Parses a single raw cell into a ColumnVal according to its declared dtype,
+// shared by read_csv and read_whitespace
+fn parse_cell(dtype: u32, elem: &str) -> Result<ColumnVal, Box<dyn Error>> {
+    match dtype {
+        1 => Ok(ColumnVal::One(elem.to_string())),
+        2 => {
+            let parsed = elem
+                .parse::<i64>()
+                .map_err(|_| MyError(format!("'{}' is not a valid bool cell", elem)))?;
+            Ok(ColumnVal::Two(parsed != 0))
+        }
+        3 => {
+            let parsed = elem
+                .parse::<f64>()
+                .map_err(|_| MyError(format!("'{}' is not a valid float cell", elem)))?;
+            Ok(ColumnVal::Three(parsed))
+        }
+        4 => {
+            let parsed = elem
+                .parse::<i64>()
+                .map_err(|_| MyError(format!("'{}' is not a valid int cell", elem)))?;
+            Ok(ColumnVal::Four(parsed))
+        }
+        _ => Err(Box::new(MyError("Unknown type".to_string()))),
+    }
+}
+
 // For returning errors
 #[derive(Debug)]
 struct MyError(String);
@@ -47,8 +116,11 @@ impl DataFrame {
         return dataframe
     }
 
-    // Reads a given csv file and implements it in the empty dataframe from new 
-    fn read_csv(&mut self, path: &str, types: &Vec<u32>) -> Result<(), Box<dyn Error>> {
+    // Reads a given csv file and implements it in the empty dataframe from new.
+    // `null_tokens` lists the cell values (e.g. "", "NA", "null") that should be
+    // stored as ColumnVal::Missing instead of being parsed
+    fn read_csv(&mut self, path: &str, types: &Vec<u32>, null_tokens: &[&str]) -> Result<(), Box<dyn Error>> {
+        let null_tokens: HashSet<&str> = null_tokens.iter().cloned().collect();
         let mut rdr = csv::ReaderBuilder::new()
             .delimiter(b',')
             .has_headers(false)
@@ -58,7 +130,7 @@ impl DataFrame {
         for result in rdr.records() {
             // Notice that we need to provide a type hint for automatic
             // deserialization.
-            let r = result.unwrap();
+            let r = result?;
             let mut row: Vec<ColumnVal> = vec![];
             if first_row {
                 for elem in r.iter() {
@@ -69,14 +141,11 @@ impl DataFrame {
                 continue;
             }
             for (i, elem) in r.iter().enumerate() {
-                match types[i] {
-
-                    1 => row.push(ColumnVal::One(elem.to_string())),
-                    2 => row.push(ColumnVal::Two(elem.parse::<i64>().unwrap() != 0)),
-                    3 => row.push(ColumnVal::Three(elem.parse::<f64>().unwrap())),
-                    4 => row.push(ColumnVal::Four(elem.parse::<i64>().unwrap())),
-                    _ => return Err(Box::new(MyError("Unknown type".to_string()))),
+                if null_tokens.contains(elem) {
+                    row.push(ColumnVal::Missing);
+                    continue;
                 }
+                row.push(parse_cell(types[i], elem)?);
             }
             // Put the data into the dataframe
             self.rows.push(row);
@@ -85,6 +154,97 @@ impl DataFrame {
         Ok(())
     }
 
+    // Reads a space-aligned table (like `ps` or `docker ps` output) where columns are
+    // separated by runs of spaces rather than commas. Column boundaries are guessed by
+    // building a histogram of how many lines have a non-space character at each position:
+    // a run of positions that are blank in most rows (see the threshold below) is a gap between columns,
+    // so a break is marked at the transition from blank to non-blank.
+    // `force_split_whitespace` skips the histogram and falls back to splitting each line on
+    // whitespace runs, which is used automatically when there aren't enough rows to trust it.
+    fn read_whitespace(
+        &mut self,
+        path: &str,
+        types: &Vec<u32>,
+        force_split_whitespace: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        const MIN_ROWS_FOR_HISTOGRAM: usize = 3;
+
+        let content = std::fs::read_to_string(path)?;
+        let lines: Vec<&str> = content.lines().collect();
+        if lines.is_empty() {
+            return Err(Box::new(MyError("no rows to read".to_string())));
+        }
+
+        // Built over bytes, matching the byte-offset slicing used below, so multi-byte
+        // UTF-8 cells don't shift the boundaries or land a slice mid-codepoint
+        let max_len = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        let mut non_space_counts = vec![0usize; max_len];
+        for line in &lines {
+            for (i, byte) in line.as_bytes().iter().enumerate() {
+                if *byte != b' ' {
+                    non_space_counts[i] += 1;
+                }
+            }
+        }
+
+        let use_histogram = !force_split_whitespace && lines.len() >= MIN_ROWS_FOR_HISTOGRAM;
+
+        // A position counts as blank when fewer than lines.len() / 2 (rounded down, at least 1)
+        // rows have content there, which keeps a handful of overlong data cells from hiding a real column break
+        let threshold = (lines.len() / 2).max(1);
+        let mut boundaries: Vec<usize> = Vec::new();
+        if use_histogram {
+            let mut prev_blank = true;
+            for i in 0..max_len {
+                let is_blank = non_space_counts[i] < threshold;
+                if prev_blank && !is_blank {
+                    boundaries.push(i);
+                }
+                prev_blank = is_blank;
+            }
+        }
+
+        let split_line = |line: &str| -> Vec<String> {
+            if use_histogram && !boundaries.is_empty() {
+                boundaries
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, &start)| {
+                        let mut start = start.min(line.len());
+                        while !line.is_char_boundary(start) {
+                            start -= 1;
+                        }
+                        let mut end = boundaries.get(idx + 1).copied().unwrap_or(line.len()).min(line.len()).max(start);
+                        while !line.is_char_boundary(end) {
+                            end -= 1;
+                        }
+                        line[start..end].trim().to_string()
+                    })
+                    .collect()
+            } else {
+                line.split_whitespace().map(|s| s.to_string()).collect()
+            }
+        };
+
+        let mut first_row = true;
+        for line in &lines {
+            let fields = split_line(line);
+            if first_row {
+                self.labels = fields;
+                first_row = false;
+                continue;
+            }
+
+            let mut row: Vec<ColumnVal> = vec![];
+            for (i, elem) in fields.iter().enumerate() {
+                row.push(parse_cell(types[i], elem)?);
+            }
+            self.rows.push(row);
+        }
+        self.types = types.clone();
+        Ok(())
+    }
+
     fn unimplemented() {}
 
     // Prints out the data frame to look nice
@@ -98,9 +258,10 @@ impl DataFrame {
             for column_value in row {
                 match column_value {
                     ColumnVal::One(val) => { print!("{:<15}", val); },
-                    ColumnVal::Two(val) => { print!("{:<15}", val); }, 
+                    ColumnVal::Two(val) => { print!("{:<15}", val); },
                     ColumnVal::Three(val) => { print!("{:<15.0}", val);},
                     ColumnVal::Four(val) => { print!("{:<15}", val); },
+                    ColumnVal::Missing => { print!("{:<15}", "NaN"); },
                 }
             }
             println!();
@@ -224,6 +385,7 @@ impl DataFrame {
                 match &row[index] {
                     ColumnVal::Three(num) => numeric_values.push(*num),
                     ColumnVal::Four(num) => numeric_values.push(*num as f64),
+                    ColumnVal::Missing => continue, // skip missing entries when aggregating
                     _ => return Err(Box::new(MyError("Numeric data not found".to_string()))),
                 }
             }
@@ -270,14 +432,538 @@ impl DataFrame {
     
         Ok(added_row) // Return the result
     }
+
+    // Reshapes long data into wide form: `columns` values become new labels,
+    // `index` values become rows, and `values` is aggregated into each cell
+    fn pivot(
+        &self,
+        index: &str,
+        columns: &str,
+        values: &str,
+        agg: AggFunc,
+    ) -> Result<DataFrame, Box<dyn Error>> {
+        let index_idx = self
+            .labels
+            .iter()
+            .position(|label| label == index)
+            .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", index))) as Box<dyn Error>)?;
+        let columns_idx = self
+            .labels
+            .iter()
+            .position(|label| label == columns)
+            .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", columns))) as Box<dyn Error>)?;
+        let values_idx = self
+            .labels
+            .iter()
+            .position(|label| label == values)
+            .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", values))) as Box<dyn Error>)?;
+
+        // First pass: map each distinct `columns` value to a dense slot
+        let mut col_map: HashMap<String, usize> = HashMap::new();
+        let mut col_keys: Vec<ColumnVal> = Vec::new();
+        let mut col_locations: Vec<usize> = Vec::with_capacity(self.rows.len());
+        for row in &self.rows {
+            let key = column_val_key(&row[columns_idx]);
+            let slot = match col_map.get(&key) {
+                Some(&slot) => slot,
+                None => {
+                    let slot = col_keys.len();
+                    col_keys.push(row[columns_idx].clone());
+                    col_map.insert(key, slot);
+                    slot
+                }
+            };
+            col_locations.push(slot);
+        }
+
+        // Second pass: map each distinct `index` value to a dense slot
+        let mut row_map: HashMap<String, usize> = HashMap::new();
+        let mut row_keys: Vec<ColumnVal> = Vec::new();
+        let mut row_locations: Vec<usize> = Vec::with_capacity(self.rows.len());
+        for row in &self.rows {
+            let key = column_val_key(&row[index_idx]);
+            let slot = match row_map.get(&key) {
+                Some(&slot) => slot,
+                None => {
+                    let slot = row_keys.len();
+                    row_keys.push(row[index_idx].clone());
+                    row_map.insert(key, slot);
+                    slot
+                }
+            };
+            row_locations.push(slot);
+        }
+
+        let n_rows = row_keys.len();
+        let n_cols = col_keys.len();
+        let mut sums = vec![0.0_f64; n_rows * n_cols];
+        let mut counts = vec![0_u32; n_rows * n_cols]; // numeric, non-missing cells; used as the Mean denominator
+        let mut occurrences = vec![0_u32; n_rows * n_cols]; // every non-missing cell; used by AggFunc::Count
+        let mut firsts: Vec<Option<ColumnVal>> = vec![None; n_rows * n_cols];
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let slot = row_locations[i] * n_cols + col_locations[i];
+            let val = &row[values_idx];
+            if firsts[slot].is_none() {
+                firsts[slot] = Some(val.clone());
+            }
+            if !matches!(val, ColumnVal::Missing) {
+                occurrences[slot] += 1;
+            }
+            // Only count cells that were actually summed, so Mean isn't diluted by Missing/non-numeric cells
+            match val {
+                ColumnVal::Three(n) => {
+                    sums[slot] += n;
+                    counts[slot] += 1;
+                }
+                ColumnVal::Four(n) => {
+                    sums[slot] += *n as f64;
+                    counts[slot] += 1;
+                }
+                _ => {}
+            }
+        }
+
+        // Assemble the result: one row per distinct index value, one column per distinct columns value
+        let mut result = DataFrame::new();
+        result.rows = vec![vec![]; n_rows];
+        result.add_column(index, self.types[index_idx], &row_keys)?;
+
+        for c in 0..n_cols {
+            let label = column_val_label(&col_keys[c]);
+            let mut col_data = Vec::with_capacity(n_rows);
+            for r in 0..n_rows {
+                let slot = r * n_cols + c;
+                let cell = match agg {
+                    AggFunc::Sum => ColumnVal::Three(sums[slot]),
+                    AggFunc::Mean => {
+                        if counts[slot] == 0 {
+                            ColumnVal::Three(0.0)
+                        } else {
+                            ColumnVal::Three(sums[slot] / counts[slot] as f64)
+                        }
+                    }
+                    AggFunc::Count => ColumnVal::Four(occurrences[slot] as i64),
+                    AggFunc::First => firsts[slot].clone().unwrap_or(ColumnVal::Missing),
+                };
+                col_data.push(cell);
+            }
+            let dtype = match agg {
+                AggFunc::Count => 4,
+                // First keeps whatever variant the source cell actually was, not always f64
+                AggFunc::First => col_data
+                    .iter()
+                    .find_map(|v| match v {
+                        ColumnVal::One(_) => Some(1),
+                        ColumnVal::Two(_) => Some(2),
+                        ColumnVal::Three(_) => Some(3),
+                        ColumnVal::Four(_) => Some(4),
+                        ColumnVal::Missing => None,
+                    })
+                    .unwrap_or(self.types[values_idx]),
+                _ => 3,
+            };
+            result.add_column(&label, dtype, &col_data)?;
+        }
+
+        Ok(result)
+    }
+
+    // Unpivots wide `value_vars` columns into a stacked "variable"/"value" pair,
+    // repeating the `id_vars` for every value column on each row
+    fn melt(&self, id_vars: &[String], value_vars: &[String]) -> Result<DataFrame, Box<dyn Error>> {
+        let id_indices: Vec<usize> = id_vars
+            .iter()
+            .map(|label| {
+                self.labels
+                    .iter()
+                    .position(|l| l == label)
+                    .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", label))) as Box<dyn Error>)
+            })
+            .collect::<Result<_, _>>()?;
+        let value_indices: Vec<usize> = value_vars
+            .iter()
+            .map(|label| {
+                self.labels
+                    .iter()
+                    .position(|l| l == label)
+                    .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", label))) as Box<dyn Error>)
+            })
+            .collect::<Result<_, _>>()?;
+
+        if value_indices.is_empty() {
+            return Err(Box::new(MyError("value_vars must not be empty".to_string())));
+        }
+
+        // All value_vars must share a type so the combined "value" column keeps one types entry
+        let value_type = self.types[value_indices[0]];
+        if value_indices.iter().any(|&idx| self.types[idx] != value_type) {
+            return Err(Box::new(MyError("value_vars must share a compatible type".to_string())));
+        }
+
+        let mut result = DataFrame::new();
+        for &idx in &id_indices {
+            result.labels.push(self.labels[idx].clone());
+            result.types.push(self.types[idx]);
+        }
+        result.labels.push("variable".to_string());
+        result.types.push(1);
+        result.labels.push("value".to_string());
+        result.types.push(value_type);
+
+        result.rows = Vec::with_capacity(self.rows.len() * value_vars.len());
+        for row in &self.rows {
+            for (&val_idx, var_name) in value_indices.iter().zip(value_vars.iter()) {
+                let mut new_row: Vec<ColumnVal> = id_indices.iter().map(|&idx| row[idx].clone()).collect();
+                new_row.push(ColumnVal::One(var_name.clone()));
+                new_row.push(row[val_idx].clone());
+                result.rows.push(new_row);
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Maps each distinct combination of `keys` column values to the row indices
+    // belonging to it, in a single pass over the rows
+    fn group_by(&self, keys: &[String]) -> Result<GroupBy, Box<dyn Error>> {
+        let key_indices: Vec<usize> = keys
+            .iter()
+            .map(|key| {
+                self.labels
+                    .iter()
+                    .position(|l| l == key)
+                    .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", key))) as Box<dyn Error>)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut group_map: HashMap<Vec<String>, usize> = HashMap::new();
+        let mut key_values: Vec<Vec<ColumnVal>> = Vec::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let key: Vec<String> = key_indices.iter().map(|&idx| column_val_key(&row[idx])).collect();
+            let group_idx = match group_map.get(&key) {
+                Some(&idx) => idx,
+                None => {
+                    let idx = groups.len();
+                    key_values.push(key_indices.iter().map(|&idx| row[idx].clone()).collect());
+                    groups.push(Vec::new());
+                    group_map.insert(key, idx);
+                    idx
+                }
+            };
+            groups[group_idx].push(i);
+        }
+
+        Ok(GroupBy {
+            dataframe: self,
+            keys: keys.to_vec(),
+            key_values,
+            groups,
+        })
+    }
+
+    // Returns a typed view over row `i` without having to pattern-match ColumnVal by hand
+    fn row(&self, i: usize) -> Row {
+        Row { dataframe: self, index: i }
+    }
+
+    // Iterates over every row as a Row
+    fn iter_rows(&self) -> impl Iterator<Item = Row> {
+        (0..self.rows.len()).map(move |i| self.row(i))
+    }
+
+    // Vertically stacks `others` onto self, matching columns by label rather than
+    // positional type equality like merge_frame does
+    fn concat(&mut self, others: &[DataFrame], how: ConcatHow) -> Result<(), Box<dyn Error>> {
+        match how {
+            ConcatHow::Strict => {
+                self.rows.reserve(others.iter().map(|o| o.rows.len()).sum());
+                for other in others {
+                    if other.labels.len() != self.labels.len() {
+                        return Err(Box::new(MyError("frame has a different set of columns".to_string())));
+                    }
+                    let reordered_indices: Vec<usize> = self
+                        .labels
+                        .iter()
+                        .enumerate()
+                        .map(|(self_idx, label)| {
+                            let other_idx = other
+                                .labels
+                                .iter()
+                                .position(|l| l == label)
+                                .ok_or_else(|| Box::new(MyError(format!("column '{}' missing from frame", label))) as Box<dyn Error>)?;
+                            if other.types[other_idx] != self.types[self_idx] {
+                                return Err(Box::new(MyError(format!("column '{}' has a mismatched type", label))) as Box<dyn Error>);
+                            }
+                            Ok(other_idx)
+                        })
+                        .collect::<Result<_, _>>()?;
+
+                    for row in &other.rows {
+                        let new_row: Vec<ColumnVal> = reordered_indices.iter().map(|&idx| row[idx].clone()).collect();
+                        self.rows.push(new_row);
+                    }
+                }
+                Ok(())
+            }
+            ConcatHow::Diagonal => {
+                let mut union_labels = self.labels.clone();
+                let mut union_types = self.types.clone();
+                for other in others {
+                    for (i, label) in other.labels.iter().enumerate() {
+                        match union_labels.iter().position(|l| l == label) {
+                            Some(existing_idx) => {
+                                if other.types[i] != union_types[existing_idx] {
+                                    return Err(Box::new(MyError(format!("column '{}' has a mismatched type", label))));
+                                }
+                            }
+                            None => {
+                                union_labels.push(label.clone());
+                                union_types.push(other.types[i]);
+                            }
+                        }
+                    }
+                }
+
+                let total_rows = self.rows.len() + others.iter().map(|o| o.rows.len()).sum::<usize>();
+                let mut new_rows = Vec::with_capacity(total_rows);
+
+                let self_indices: Vec<Option<usize>> = union_labels
+                    .iter()
+                    .map(|label| self.labels.iter().position(|l| l == label))
+                    .collect();
+                for row in &self.rows {
+                    new_rows.push(
+                        self_indices
+                            .iter()
+                            .map(|maybe_idx| match maybe_idx {
+                                Some(idx) => row[*idx].clone(),
+                                None => ColumnVal::Missing,
+                            })
+                            .collect(),
+                    );
+                }
+
+                for other in others {
+                    let other_indices: Vec<Option<usize>> = union_labels
+                        .iter()
+                        .map(|label| other.labels.iter().position(|l| l == label))
+                        .collect();
+                    for row in &other.rows {
+                        new_rows.push(
+                            other_indices
+                                .iter()
+                                .map(|maybe_idx| match maybe_idx {
+                                    Some(idx) => row[*idx].clone(),
+                                    None => ColumnVal::Missing,
+                                })
+                                .collect(),
+                        );
+                    }
+                }
+
+                self.labels = union_labels;
+                self.types = union_types;
+                self.rows = new_rows;
+                Ok(())
+            }
+        }
+    }
 }
 
+// A single row of a DataFrame, indexable by column label or position and with
+// typed getters that validate against the stored ColumnVal variant
+struct Row<'a> {
+    dataframe: &'a DataFrame,
+    index: usize,
+}
+
+impl<'a> Row<'a> {
+    fn column_index(&self, col: &str) -> Result<usize, Box<dyn Error>> {
+        self.dataframe
+            .labels
+            .iter()
+            .position(|l| l == col)
+            .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", col))) as Box<dyn Error>)
+    }
+
+    fn get(&self, col: &str) -> Result<&ColumnVal, Box<dyn Error>> {
+        let idx = self.column_index(col)?;
+        Ok(&self.dataframe.rows[self.index][idx])
+    }
+
+    fn get_f64(&self, col: &str) -> Result<f64, Box<dyn Error>> {
+        match self.get(col)? {
+            ColumnVal::Three(n) => Ok(*n),
+            ColumnVal::Four(n) => Ok(*n as f64),
+            _ => Err(Box::new(MyError(format!("column '{}' is not numeric", col)))),
+        }
+    }
+
+    fn get_str(&self, col: &str) -> Result<&str, Box<dyn Error>> {
+        match self.get(col)? {
+            ColumnVal::One(s) => Ok(s.as_str()),
+            _ => Err(Box::new(MyError(format!("column '{}' is not a string", col)))),
+        }
+    }
+
+    fn get_bool(&self, col: &str) -> Result<bool, Box<dyn Error>> {
+        match self.get(col)? {
+            ColumnVal::Two(b) => Ok(*b),
+            _ => Err(Box::new(MyError(format!("column '{}' is not a bool", col)))),
+        }
+    }
+
+    fn get_i64(&self, col: &str) -> Result<i64, Box<dyn Error>> {
+        match self.get(col)? {
+            ColumnVal::Four(i) => Ok(*i),
+            _ => Err(Box::new(MyError(format!("column '{}' is not an int", col)))),
+        }
+    }
+}
+
+impl<'a> std::ops::Index<&str> for Row<'a> {
+    type Output = ColumnVal;
+    fn index(&self, col: &str) -> &ColumnVal {
+        self.get(col).expect("column not found")
+    }
+}
+
+impl<'a> std::ops::Index<usize> for Row<'a> {
+    type Output = ColumnVal;
+    fn index(&self, pos: usize) -> &ColumnVal {
+        &self.dataframe.rows[self.index][pos]
+    }
+}
+
+// Handle returned by DataFrame::group_by: the distinct key combinations seen
+// and the row indices belonging to each one
+struct GroupBy<'a> {
+    dataframe: &'a DataFrame,
+    keys: Vec<String>,
+    key_values: Vec<Vec<ColumnVal>>, // one entry per group, values of the key columns
+    groups: Vec<Vec<usize>>,         // row indices belonging to each group, aligned with key_values
+}
+
+impl<'a> GroupBy<'a> {
+    // Builds the key columns shared by every aggregation result
+    fn key_columns(&self) -> Result<DataFrame, Box<dyn Error>> {
+        let mut result = DataFrame::new();
+        result.rows = vec![vec![]; self.groups.len()];
+        for (i, key) in self.keys.iter().enumerate() {
+            let idx = self
+                .dataframe
+                .labels
+                .iter()
+                .position(|l| l == key)
+                .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", key))) as Box<dyn Error>)?;
+            let column_data: Vec<ColumnVal> = self.key_values.iter().map(|kv| kv[i].clone()).collect();
+            result.add_column(key, self.dataframe.types[idx], &column_data)?;
+        }
+        Ok(result)
+    }
+
+    // Folds a numeric value column over each group's row indices. `f` returns None when
+    // a group has no numeric cells to fold, which becomes ColumnVal::Missing rather than
+    // the fold's identity value leaking into the result.
+    fn aggregate_numeric(&self, value_col: &str, f: fn(&[f64]) -> Option<f64>) -> Result<DataFrame, Box<dyn Error>> {
+        let value_idx = self
+            .dataframe
+            .labels
+            .iter()
+            .position(|l| l == value_col)
+            .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", value_col))) as Box<dyn Error>)?;
+
+        let mut result = self.key_columns()?;
+
+        let agg_values: Vec<ColumnVal> = self
+            .groups
+            .iter()
+            .map(|indices| {
+                let nums: Vec<f64> = indices
+                    .iter()
+                    .filter_map(|&i| match &self.dataframe.rows[i][value_idx] {
+                        ColumnVal::Three(n) => Some(*n),
+                        ColumnVal::Four(n) => Some(*n as f64),
+                        _ => None,
+                    })
+                    .collect();
+                match f(&nums) {
+                    Some(n) => ColumnVal::Three(n),
+                    None => ColumnVal::Missing,
+                }
+            })
+            .collect();
+        result.add_column(value_col, 3, &agg_values)?;
+
+        Ok(result)
+    }
+
+    fn agg_mean(&self, value_col: &str) -> Result<DataFrame, Box<dyn Error>> {
+        self.aggregate_numeric(value_col, |vals| {
+            if vals.is_empty() {
+                None
+            } else {
+                Some(vals.iter().sum::<f64>() / vals.len() as f64)
+            }
+        })
+    }
+
+    fn agg_sum(&self, value_col: &str) -> Result<DataFrame, Box<dyn Error>> {
+        self.aggregate_numeric(value_col, |vals| {
+            if vals.is_empty() {
+                None
+            } else {
+                Some(vals.iter().sum())
+            }
+        })
+    }
+
+    // Missing rather than +-infinity when a group has no numeric cells to fold
+    fn agg_min(&self, value_col: &str) -> Result<DataFrame, Box<dyn Error>> {
+        self.aggregate_numeric(value_col, |vals| {
+            if vals.is_empty() {
+                None
+            } else {
+                Some(vals.iter().cloned().fold(f64::INFINITY, f64::min))
+            }
+        })
+    }
+
+    fn agg_max(&self, value_col: &str) -> Result<DataFrame, Box<dyn Error>> {
+        self.aggregate_numeric(value_col, |vals| {
+            if vals.is_empty() {
+                None
+            } else {
+                Some(vals.iter().cloned().fold(f64::NEG_INFINITY, f64::max))
+            }
+        })
+    }
+
+    // Counts the rows in each group; value_col only needs to exist, not be numeric
+    fn agg_count(&self, value_col: &str) -> Result<DataFrame, Box<dyn Error>> {
+        self.dataframe
+            .labels
+            .iter()
+            .position(|l| l == value_col)
+            .ok_or_else(|| Box::new(MyError(format!("column '{}' doesn't exist", value_col))) as Box<dyn Error>)?;
+
+        let mut result = self.key_columns()?;
+        let counts: Vec<ColumnVal> = self.groups.iter().map(|indices| ColumnVal::Four(indices.len() as i64)).collect();
+        result.add_column(value_col, 4, &counts)?;
+
+        Ok(result)
+    }
+}
 
 fn main() -> Result<(), Box<dyn Error>> {
     //printing the original data frmae
     let mut dataframe = DataFrame::new();
     let types = vec![1, 4, 3, 4, 4, 2];
-    dataframe.read_csv("pizza.csv", &types)?; //read it
+    let null_tokens = ["", "NA", "null", "NaN"];
+    dataframe.read_csv("pizza.csv", &types, &null_tokens)?; //read it
     println!("Original dataframe:");
     dataframe.print(); // print
 
@@ -298,8 +984,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("");
     let mut dataframe_og = DataFrame::new();
     let mut dataframe2 = DataFrame::new();
-    dataframe_og.read_csv("pizza.csv", &types)?;
-    dataframe2.read_csv("pizza2.csv", &types)?;
+    dataframe_og.read_csv("pizza.csv", &types, &null_tokens)?;
+    dataframe2.read_csv("pizza2.csv", &types, &null_tokens)?;
     dataframe_og.merge_frame(dataframe2)?;
     println!("Merged DataFrame:"); // Print the merged DataFrame
     dataframe_og.print();
@@ -308,7 +994,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("");
     println!("");
     let mut select_df = DataFrame::new();
-    select_df.read_csv("pizza.csv", &types)?;
+    select_df.read_csv("pizza.csv", &types, &null_tokens)?;
     let selected_df =select_df.restrict_columns(vec!["Name".to_string(),"Number".to_string()])?;
     println!("Selected Columns DataFrame:");
     selected_df.print();
@@ -317,7 +1003,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("");
     println!("");
     let mut unfiltered_df = DataFrame::new();
-    unfiltered_df.read_csv("pizza.csv", &types)?; //make unfitierd df pizza
+    unfiltered_df.read_csv("pizza.csv", &types, &null_tokens)?; //make unfitierd df pizza
     let filtered_df = unfiltered_df.filter("LikesPizza", |val| {
     //filter by pizza column where values are true
     matches!(val, ColumnVal::Two(true))})?;
@@ -326,7 +1012,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // column op
     let mut df = DataFrame::new();
-    df.read_csv("pizza.csv", &types)?;
+    df.read_csv("pizza.csv", &types, &null_tokens)?;
     let ppg_column = df.column_op(&["PPG".to_string()])?;
     println!("PPG Column: {:?}", ppg_column); // Should print: [24.6, 25.0, 27.0, 25.0, 30.1]
 
@@ -339,6 +1025,63 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("Added Rows (PPG + TotalPoints): {:?}", added_rows);
     // Expected output something like: [38311.6, 36953.0, 36408.0, 33668.0, 32322.1]
 
+    // pivot: average PPG per player, split out by whether they like pizza
+    println!("");
+    println!("");
+    let mut pivot_df = DataFrame::new();
+    pivot_df.read_csv("pizza.csv", &types, &null_tokens)?;
+    let pivoted = pivot_df.pivot("Name", "LikesPizza", "PPG", AggFunc::Mean)?;
+    println!("Pivoted DataFrame (Name x LikesPizza, mean PPG):");
+    pivoted.print();
+
+    // melt: stack the "Number" and "TotalPoints" columns into variable/value rows
+    println!("");
+    println!("");
+    let mut melt_df = DataFrame::new();
+    melt_df.read_csv("pizza.csv", &types, &null_tokens)?;
+    let melted = melt_df.melt(&["Name".to_string()], &["Number".to_string(), "TotalPoints".to_string()])?;
+    println!("Melted DataFrame:");
+    melted.print();
+
+    // group_by: average PPG for players who do/don't like pizza
+    println!("");
+    println!("");
+    let mut group_df = DataFrame::new();
+    group_df.read_csv("pizza.csv", &types, &null_tokens)?;
+    let grouped = group_df.group_by(&["LikesPizza".to_string()])?;
+    let grouped_ppg = grouped.agg_mean("PPG")?;
+    println!("Grouped DataFrame (mean PPG by LikesPizza):");
+    grouped_ppg.print();
+
+    // read_whitespace: load a space-aligned table like `pizza.csv` but whitespace-padded
+    println!("");
+    println!("");
+    let mut whitespace_df = DataFrame::new();
+    whitespace_df.read_whitespace("pizza.txt", &types, false)?;
+    println!("Whitespace-aligned DataFrame:");
+    whitespace_df.print();
+
+    // Row: iterate rows with typed getters instead of matching ColumnVal by hand
+    println!("");
+    println!("");
+    let mut row_df = DataFrame::new();
+    row_df.read_csv("pizza.csv", &types, &null_tokens)?;
+    println!("Row-wise iteration:");
+    for row in row_df.iter_rows() {
+        println!("{} has a PPG of {:.1}", row.get_str("Name")?, row.get_f64("PPG")?);
+    }
+
+    // concat: stack two frames by matching on labels instead of merge_frame's strict type equality
+    println!("");
+    println!("");
+    let mut concat_base = DataFrame::new();
+    let mut concat_other = DataFrame::new();
+    concat_base.read_csv("pizza.csv", &types, &null_tokens)?;
+    concat_other.read_csv("pizza2.csv", &types, &null_tokens)?;
+    concat_base.concat(&[concat_other], ConcatHow::Diagonal)?;
+    println!("Concatenated DataFrame:");
+    concat_base.print();
+
     Ok(())
 }
 